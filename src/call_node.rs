@@ -12,6 +12,17 @@ pub struct RawEvent {
     pub library_name: String,
 }
 
+/// Aggregated timing statistics for a single `(module, function)` pair,
+/// accumulated across every call site in the tree.
+#[derive(Debug, Clone, Default)]
+pub struct FuncStats {
+    pub calls: u64,
+    pub total_duration_ns: u64,
+    pub self_duration_ns: u64,
+    pub min_duration_ns: u64,
+    pub max_duration_ns: u64,
+}
+
 #[allow(dead_code)]
 pub struct CallNode {
     pub func_name: String,
@@ -23,10 +34,16 @@ pub struct CallNode {
     pub duration_ns: u64,
     pub is_external: bool,
     pub library_name: String,
+    /// The original mangled symbol, when `func_name` was demangled from a
+    /// compiled-extension frame (pyo3/Cython native symbols, Rust v0/legacy
+    /// mangling). `None` for ordinary Python frames and symbols that were
+    /// already plain.
+    pub raw_symbol: Option<String>,
     pub children: Vec<CallNode>,
 }
 
 impl CallNode {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         func_name: String,
         module_name: String,
@@ -35,6 +52,7 @@ impl CallNode {
         start_time_ns: u64,
         is_external: bool,
         library_name: String,
+        raw_symbol: Option<String>,
     ) -> Self {
         CallNode {
             func_name,
@@ -46,6 +64,7 @@ impl CallNode {
             duration_ns: 0,
             is_external,
             library_name,
+            raw_symbol,
             children: Vec::new(),
         }
     }
@@ -55,6 +74,30 @@ impl CallNode {
         self.duration_ns = end_time_ns.saturating_sub(self.start_time_ns);
     }
 
+    /// Find the node with the given pre-order index, using the same
+    /// numbering scheme as [`CallNode::find_slowest_id`] (root is `0`,
+    /// then a depth-first walk over all children).
+    pub fn find_by_id(&self, target_id: usize) -> Option<&CallNode> {
+        let mut counter: usize = 0;
+        self.find_by_id_recursive(target_id, &mut counter)
+    }
+
+    fn find_by_id_recursive(&self, target_id: usize, counter: &mut usize) -> Option<&CallNode> {
+        let my_id = *counter;
+        *counter += 1;
+
+        if my_id == target_id {
+            return Some(self);
+        }
+
+        for child in &self.children {
+            if let Some(found) = child.find_by_id_recursive(target_id, counter) {
+                return Some(found);
+            }
+        }
+        None
+    }
+
     /// Find the slowest internal (non-root, non-external) node by duration.
     /// Returns the index path or a unique key for identification.
     pub fn find_slowest_id(&self) -> Option<usize> {
@@ -93,6 +136,43 @@ impl CallNode {
             child.normalize_times(base_ns);
         }
     }
+
+    /// Walk the tree (excluding the synthetic root) and build per-function
+    /// statistics keyed by `(module_name, func_name)`, accumulating call
+    /// count, cumulative duration, self time, and min/max duration.
+    ///
+    /// Self time is `duration_ns` minus the sum of direct children's
+    /// `duration_ns`; external nodes have no children, so their full
+    /// duration counts as self time.
+    pub fn aggregate_stats(&self) -> HashMap<(String, String), FuncStats> {
+        let mut stats = HashMap::new();
+        for child in &self.children {
+            child.aggregate_stats_recursive(&mut stats);
+        }
+        stats
+    }
+
+    fn aggregate_stats_recursive(&self, stats: &mut HashMap<(String, String), FuncStats>) {
+        let children_duration_ns: u64 = self.children.iter().map(|c| c.duration_ns).sum();
+        let self_duration_ns = self.duration_ns.saturating_sub(children_duration_ns);
+
+        let key = (self.module_name.clone(), self.func_name.clone());
+        let entry = stats.entry(key).or_default();
+        if entry.calls == 0 {
+            entry.min_duration_ns = self.duration_ns;
+            entry.max_duration_ns = self.duration_ns;
+        } else {
+            entry.min_duration_ns = entry.min_duration_ns.min(self.duration_ns);
+            entry.max_duration_ns = entry.max_duration_ns.max(self.duration_ns);
+        }
+        entry.calls += 1;
+        entry.total_duration_ns += self.duration_ns;
+        entry.self_duration_ns += self_duration_ns;
+
+        for child in &self.children {
+            child.aggregate_stats_recursive(stats);
+        }
+    }
 }
 
 fn extract_string(py: Python<'_>, map: &HashMap<String, Py<PyAny>>, key: &str) -> PyResult<String> {
@@ -139,6 +219,24 @@ pub fn parse_events(
     Ok(result)
 }
 
+/// Detect a mangled compiled-extension symbol (Rust legacy `_ZN...`/`__Z...`
+/// or the Rust v0 scheme `_R...`) and demangle it via `rustc_demangle`,
+/// the same routine the standard library uses.
+///
+/// Returns `Some((demangled, original))` when the name was recognized and
+/// demangling changed it, `None` otherwise.
+fn demangle_symbol(func_name: &str) -> Option<(String, String)> {
+    if !(func_name.starts_with("_ZN") || func_name.starts_with("_R") || func_name.starts_with("__Z"))
+    {
+        return None;
+    }
+    let demangled = rustc_demangle::demangle(func_name).to_string();
+    if demangled == func_name {
+        return None;
+    }
+    Some((demangled, func_name.to_string()))
+}
+
 pub fn build_call_tree(
     events: Vec<RawEvent>,
     api_name: &str,
@@ -150,14 +248,19 @@ pub fn build_call_tree(
     for ev in events {
         match ev.event.as_str() {
             "call" | "c_call" => {
+                let (func_name, raw_symbol) = match demangle_symbol(&ev.func_name) {
+                    Some((demangled, raw)) => (demangled, Some(raw)),
+                    None => (ev.func_name, None),
+                };
                 let node = CallNode::new(
-                    ev.func_name,
+                    func_name,
                     ev.module,
                     ev.filename,
                     ev.lineno,
                     ev.timestamp_ns,
                     ev.is_external,
                     ev.library_name,
+                    raw_symbol,
                 );
                 stack.push(node);
             }
@@ -205,6 +308,7 @@ pub fn build_call_tree(
             start_ns,
             false,
             String::new(),
+            None,
         )
     });
 
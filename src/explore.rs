@@ -0,0 +1,378 @@
+use crate::call_node::{build_call_tree, parse_events, CallNode};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use pyo3::prelude::*;
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
+use ratatui::Terminal;
+use std::collections::HashMap as StdHashMap;
+use std::collections::HashSet;
+use std::io;
+use std::time::Duration;
+
+/// One row of the flattened, currently-visible call tree.
+///
+/// `id` is the node's pre-order index, using the same numbering scheme as
+/// [`CallNode::find_slowest_id`] and [`CallNode::find_by_id`]. `node` is
+/// borrowed directly from the tree built once in [`explore_events`], so
+/// drawing a row never needs to re-walk the tree to look `id` back up.
+struct FlatRow<'a> {
+    id: usize,
+    depth: usize,
+    has_children: bool,
+    node: &'a CallNode,
+}
+
+/// Interactive state for the terminal explorer: which nodes are expanded,
+/// the current flattened view, cursor position, and in-progress search.
+struct ExploreState<'a> {
+    expanded: HashSet<usize>,
+    rows: Vec<FlatRow<'a>>,
+    selected: usize,
+    search_mode: bool,
+    search_query: String,
+}
+
+impl<'a> ExploreState<'a> {
+    fn new(root: &'a CallNode) -> Self {
+        let mut state = ExploreState {
+            expanded: HashSet::new(),
+            rows: Vec::new(),
+            selected: 0,
+            search_mode: false,
+            search_query: String::new(),
+        };
+        // Expand the root by default so the first screen isn't empty.
+        state.expanded.insert(0);
+        state.rebuild_rows(root);
+        state
+    }
+
+    fn rebuild_rows(&mut self, root: &'a CallNode) {
+        self.rows.clear();
+        let mut counter: usize = 0;
+        flatten_visible(root, 0, &mut counter, &self.expanded, true, &mut self.rows);
+        if self.selected >= self.rows.len() {
+            self.selected = self.rows.len().saturating_sub(1);
+        }
+    }
+
+    fn selected_id(&self) -> Option<usize> {
+        self.rows.get(self.selected).map(|r| r.id)
+    }
+
+    fn selected_node(&self) -> Option<&'a CallNode> {
+        self.rows.get(self.selected).map(|r| r.node)
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        if self.rows.is_empty() {
+            return;
+        }
+        let max = self.rows.len() as isize - 1;
+        let new_pos = (self.selected as isize + delta).clamp(0, max);
+        self.selected = new_pos as usize;
+    }
+
+    fn toggle_selected(&mut self, root: &'a CallNode) {
+        if let Some(row) = self.rows.get(self.selected) {
+            if row.has_children {
+                if self.expanded.contains(&row.id) {
+                    self.expanded.remove(&row.id);
+                } else {
+                    self.expanded.insert(row.id);
+                }
+            }
+        }
+        self.rebuild_rows(root);
+    }
+
+    /// Select the given node id, expanding its ancestors so it becomes visible.
+    fn reveal(&mut self, root: &'a CallNode, target_id: usize) {
+        expand_ancestors(root, 0, target_id, &mut self.expanded);
+        self.rebuild_rows(root);
+        if let Some(pos) = self.rows.iter().position(|r| r.id == target_id) {
+            self.selected = pos;
+        }
+    }
+
+    /// Jump to the next node (after the current selection, wrapping around)
+    /// whose function, module or library name contains the search query.
+    fn jump_to_next_match(&mut self, root: &'a CallNode) {
+        if self.search_query.is_empty() {
+            return;
+        }
+        let query = self.search_query.to_lowercase();
+        let mut counter: usize = 0;
+        let mut matches = Vec::new();
+        collect_matches(root, &query, &mut counter, &mut matches);
+        if matches.is_empty() {
+            return;
+        }
+        let current = self.selected_id().unwrap_or(0);
+        let next = matches
+            .iter()
+            .find(|&&id| id > current)
+            .copied()
+            .unwrap_or(matches[0]);
+        self.reveal(root, next);
+    }
+}
+
+fn flatten_visible<'a>(
+    node: &'a CallNode,
+    depth: usize,
+    counter: &mut usize,
+    expanded: &HashSet<usize>,
+    visible: bool,
+    rows: &mut Vec<FlatRow<'a>>,
+) {
+    let id = *counter;
+    *counter += 1;
+
+    if visible {
+        rows.push(FlatRow {
+            id,
+            depth,
+            has_children: !node.children.is_empty(),
+            node,
+        });
+    }
+
+    let children_visible = visible && expanded.contains(&id);
+    for child in &node.children {
+        flatten_visible(child, depth + 1, counter, expanded, children_visible, rows);
+    }
+}
+
+fn expand_ancestors(node: &CallNode, id: usize, target_id: usize, expanded: &mut HashSet<usize>) -> bool {
+    if id == target_id {
+        return true;
+    }
+    let mut counter = id + 1;
+    for child in &node.children {
+        let child_id = counter;
+        let subtree_size = count_subtree(child);
+        if target_id >= child_id && target_id < child_id + subtree_size {
+            expanded.insert(id);
+            expand_ancestors(child, child_id, target_id, expanded);
+            return true;
+        }
+        counter += subtree_size;
+    }
+    false
+}
+
+fn count_subtree(node: &CallNode) -> usize {
+    1 + node.children.iter().map(count_subtree).sum::<usize>()
+}
+
+fn collect_matches(node: &CallNode, query: &str, counter: &mut usize, matches: &mut Vec<usize>) {
+    let id = *counter;
+    *counter += 1;
+
+    if node.func_name.to_lowercase().contains(query)
+        || node.module_name.to_lowercase().contains(query)
+        || node.library_name.to_lowercase().contains(query)
+    {
+        matches.push(id);
+    }
+
+    for child in &node.children {
+        collect_matches(child, query, counter, matches);
+    }
+}
+
+fn format_duration(ns: u64) -> String {
+    if ns < 1_000 {
+        format!("{}ns", ns)
+    } else if ns < 1_000_000 {
+        format!("{:.2}\u{00b5}s", ns as f64 / 1_000.0)
+    } else if ns < 1_000_000_000 {
+        format!("{:.2}ms", ns as f64 / 1_000_000.0)
+    } else {
+        format!("{:.3}s", ns as f64 / 1_000_000_000.0)
+    }
+}
+
+fn draw(frame: &mut ratatui::Frame, state: &mut ExploreState, slowest_id: Option<usize>) {
+    let outer = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(1)])
+        .split(frame.area());
+
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(outer[0]);
+
+    let items: Vec<ListItem> = state
+        .rows
+        .iter()
+        .map(|row| {
+            let node = row.node;
+            let indent = "  ".repeat(row.depth);
+            let marker = if row.has_children {
+                if state.expanded.contains(&row.id) {
+                    "\u{25BC}"
+                } else {
+                    "\u{25B6}"
+                }
+            } else {
+                " "
+            };
+            let mut style = Style::default();
+            if node.is_external {
+                style = style.fg(Color::DarkGray);
+            }
+            if slowest_id == Some(row.id) {
+                style = style.fg(Color::Red).add_modifier(Modifier::BOLD);
+            }
+            let line = Line::from(vec![Span::raw(format!(
+                "{indent}{marker} {} ({})",
+                node.func_name,
+                format_duration(node.duration_ns)
+            ))]);
+            ListItem::new(line).style(style)
+        })
+        .collect();
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(state.selected));
+
+    let title = if state.search_mode {
+        format!("Call Tree — /{}", state.search_query)
+    } else {
+        "Call Tree".to_string()
+    };
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    frame.render_stateful_widget(list, body[0], &mut list_state);
+
+    let detail_text = match state.selected_node() {
+        Some(node) => format!(
+            "Function: {}\nModule: {}\nFile: {}:{}\nLibrary: {}\nDuration: {}\nExternal: {}",
+            node.func_name,
+            node.module_name,
+            node.file_path,
+            node.line_number,
+            if node.library_name.is_empty() {
+                "-"
+            } else {
+                &node.library_name
+            },
+            format_duration(node.duration_ns),
+            node.is_external,
+        ),
+        None => String::new(),
+    };
+    let detail = Paragraph::new(detail_text).block(Block::default().borders(Borders::ALL).title("Detail"));
+    frame.render_widget(detail, body[1]);
+
+    let help = if state.search_mode {
+        "Enter: confirm search  Esc: cancel"
+    } else {
+        "↑/↓: move  Enter/Space: expand  /: search  n: next match  s: slowest  q: quit"
+    };
+    frame.render_widget(Paragraph::new(help), outer[1]);
+}
+
+fn run_explorer(root: &CallNode) -> io::Result<()> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let slowest_id = root.find_slowest_id();
+    let mut state = ExploreState::new(root);
+
+    let result = (|| -> io::Result<()> {
+        loop {
+            terminal.draw(|frame| draw(frame, &mut state, slowest_id))?;
+
+            if !event::poll(Duration::from_millis(200))? {
+                continue;
+            }
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            if state.search_mode {
+                match key.code {
+                    KeyCode::Enter => {
+                        state.search_mode = false;
+                        state.jump_to_next_match(root);
+                    }
+                    KeyCode::Esc => {
+                        state.search_mode = false;
+                        state.search_query.clear();
+                    }
+                    KeyCode::Backspace => {
+                        state.search_query.pop();
+                    }
+                    KeyCode::Char(c) => {
+                        state.search_query.push(c);
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => break,
+                KeyCode::Up => state.move_selection(-1),
+                KeyCode::Down => state.move_selection(1),
+                KeyCode::Enter | KeyCode::Char(' ') => state.toggle_selected(root),
+                KeyCode::Char('/') => {
+                    state.search_mode = true;
+                    state.search_query.clear();
+                }
+                KeyCode::Char('n') => state.jump_to_next_match(root),
+                KeyCode::Char('s') => {
+                    if let Some(id) = slowest_id {
+                        state.reveal(root, id);
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    })();
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+
+    result
+}
+
+/// Launch an interactive terminal explorer over a profile, reusing the same
+/// event parsing and tree-building pipeline as [`crate::process_events`].
+/// Blocks until the user quits the explorer (`q`/Esc).
+#[pyfunction]
+pub fn explore_events(
+    py: Python<'_>,
+    events: Vec<StdHashMap<String, Py<PyAny>>>,
+    api_name: &str,
+    start_ns: u64,
+    end_ns: u64,
+) -> PyResult<()> {
+    let raw_events = parse_events(py, &events)?;
+    let root = build_call_tree(raw_events, api_name, start_ns, end_ns);
+    // The GIL is only needed to pull events out of Python above; the
+    // explorer itself just blocks on terminal I/O, so release it for the
+    // whole interactive session instead of freezing every other Python
+    // thread until the user quits.
+    py.allow_threads(|| run_explorer(&root))
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))
+}
@@ -1,29 +1,49 @@
+//! External crates this module depends on beyond the standard library:
+//! `pyo3`, `rustc_demangle` (symbol demangling in [`call_node`]), and
+//! `ratatui`/`crossterm` (the terminal explorer in [`explore`]). This tree
+//! is a source snapshot without its own `Cargo.toml`; the out-of-tree
+//! package manifest is the source of truth for version pins.
+
 use pyo3::prelude::*;
 use std::collections::HashMap;
 
 mod call_node;
+mod explore;
 mod reporter;
 
 use call_node::{build_call_tree, parse_events};
-use reporter::generate_html;
+use explore::explore_events;
+use reporter::{generate_html, RenderLimits};
 
 /// Process profiling events and generate an HTML call graph report.
+///
+/// `max_depth` and `max_nodes` bound the size of the rendered page: once
+/// either is exceeded, the remaining subtree is collapsed into a single
+/// placeholder that expands on click instead of being rendered up front.
 #[pyfunction]
+#[pyo3(signature = (events, api_name, start_ns, end_ns, max_depth=200, max_nodes=20_000))]
 fn process_events(
     py: Python<'_>,
     events: Vec<HashMap<String, Py<PyAny>>>,
     api_name: &str,
     start_ns: u64,
     end_ns: u64,
+    max_depth: usize,
+    max_nodes: usize,
 ) -> PyResult<String> {
     let raw_events = parse_events(py, &events)?;
     let root = build_call_tree(raw_events, api_name, start_ns, end_ns);
-    let html = generate_html(&root, api_name);
+    let limits = RenderLimits {
+        max_depth,
+        max_nodes,
+    };
+    let html = generate_html(&root, api_name, &limits);
     Ok(html)
 }
 
 #[pymodule]
 fn _callprofiler(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(process_events, m)?)?;
+    m.add_function(wrap_pyfunction!(explore_events, m)?)?;
     Ok(())
 }
@@ -1,4 +1,5 @@
-use crate::call_node::CallNode;
+use crate::call_node::{CallNode, FuncStats};
+use std::collections::HashMap;
 use std::fmt::Write;
 
 fn format_duration(ns: u64) -> String {
@@ -20,6 +21,53 @@ fn html_escape(s: &str) -> String {
         .replace('"', "&quot;")
 }
 
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            // This JSON is always embedded in a <script> block (see
+            // render_elided_placeholder), so `<` must be escaped too -
+            // otherwise a frame name/path containing `</script>` would
+            // terminate the block early and corrupt everything after it.
+            '<' => out.push_str("\\u003c"),
+            c if (c as u32) < 0x20 => {
+                write!(out, "\\u{:04x}", c as u32).unwrap();
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Output budget for [`generate_html`], modeled on rustdoc's
+/// `html::length_limit`: bounds tree depth and, roughly, rendered node
+/// count so huge/deep traces don't produce a multi-megabyte page. Once a
+/// node is past `max_depth` or the page has rendered `max_nodes` nodes,
+/// its children are replaced with a collapsed summary that expands on
+/// click. The node budget is checked only before descending into a node's
+/// children, so siblings already being rendered are all emitted, and each
+/// placeholder's embedded subtree has its own `max_nodes` budget. Summaries
+/// nested inside an embedded subtree only show the count and total
+/// duration of the frames they hide and do not expand.
+pub struct RenderLimits {
+    pub max_depth: usize,
+    pub max_nodes: usize,
+}
+
+impl Default for RenderLimits {
+    fn default() -> Self {
+        RenderLimits {
+            max_depth: 200,
+            max_nodes: 20_000,
+        }
+    }
+}
+
 fn short_path(file_path: &str) -> &str {
     file_path
         .rsplit('/')
@@ -28,7 +76,7 @@ fn short_path(file_path: &str) -> &str {
         .unwrap_or(file_path)
 }
 
-pub fn generate_html(root: &CallNode, api_name: &str) -> String {
+pub fn generate_html(root: &CallNode, api_name: &str, limits: &RenderLimits) -> String {
     let slowest_id = root.find_slowest_id();
 
     let mut html = String::with_capacity(16384);
@@ -44,39 +92,156 @@ pub fn generate_html(root: &CallNode, api_name: &str) -> String {
 <title>callprofiler: {api_name}</title>
 <style>
 * {{ margin: 0; padding: 0; box-sizing: border-box; }}
-body {{ font-family: 'Segoe UI', -apple-system, BlinkMacSystemFont, sans-serif; margin: 24px; background: #f8f9fa; color: #212529; }}
-h1 {{ font-size: 1.5rem; color: #1a1a2e; margin-bottom: 16px; padding-bottom: 10px; border-bottom: 3px solid #4361ee; }}
-.summary {{ background: #e9ecef; padding: 14px 20px; border-radius: 8px; margin-bottom: 20px; display: flex; gap: 32px; flex-wrap: wrap; font-size: 0.9rem; }}
+:root {{
+  --bg: #f8f9fa;
+  --fg: #212529;
+  --h1-color: #1a1a2e;
+  --h1-border: #4361ee;
+  --summary-bg: #e9ecef;
+  --label-color: #495057;
+  --value-color: #212529;
+  --slowest-name-color: #e63946;
+  --tree-border: #dee2e6;
+  --node-bg: #fff;
+  --node-border: #dee2e6;
+  --node-hover-shadow: rgba(0,0,0,0.08);
+  --external-bg: #f1f3f5;
+  --external-border: #ced4da;
+  --external-color: #868e96;
+  --slowest-bg: #e63946;
+  --slowest-border: #c1121f;
+  --slowest-fg: #fff;
+  --slowest-sub-fg: rgba(255,255,255,0.85);
+  --func-name-color: #1a1a2e;
+  --location-color: #6c757d;
+  --duration-color: #4361ee;
+  --times-color: #adb5bd;
+  --badge-bg: #dee2e6;
+  --badge-color: #495057;
+  --slowest-badge-bg: rgba(255,255,255,0.25);
+  --slowest-badge-color: #fff;
+  --toggle-color: #868e96;
+  --toggle-hover-color: #4361ee;
+}}
+[data-theme="dark"] {{
+  --bg: #1a1b26;
+  --fg: #c0caf5;
+  --h1-color: #e0e4fc;
+  --h1-border: #7aa2f7;
+  --summary-bg: #24283b;
+  --label-color: #a9b1d6;
+  --value-color: #c0caf5;
+  --slowest-name-color: #f7768e;
+  --tree-border: #3b4261;
+  --node-bg: #1f2335;
+  --node-border: #3b4261;
+  --node-hover-shadow: rgba(0,0,0,0.4);
+  --external-bg: #1a1b26;
+  --external-border: #3b4261;
+  --external-color: #565f89;
+  --slowest-bg: #703436;
+  --slowest-border: #f7768e;
+  --slowest-fg: #fff;
+  --slowest-sub-fg: rgba(255,255,255,0.85);
+  --func-name-color: #c0caf5;
+  --location-color: #7982a9;
+  --duration-color: #7aa2f7;
+  --times-color: #565f89;
+  --badge-bg: #3b4261;
+  --badge-color: #a9b1d6;
+  --slowest-badge-bg: rgba(255,255,255,0.2);
+  --slowest-badge-color: #fff;
+  --toggle-color: #565f89;
+  --toggle-hover-color: #7aa2f7;
+}}
+[data-theme="ayu"] {{
+  --bg: #0f1419;
+  --fg: #bfbab0;
+  --h1-color: #e6b450;
+  --h1-border: #e6b450;
+  --summary-bg: #131721;
+  --label-color: #e6b450;
+  --value-color: #bfbab0;
+  --slowest-name-color: #ff3333;
+  --tree-border: #2d3640;
+  --node-bg: #0d1016;
+  --node-border: #2d3640;
+  --node-hover-shadow: rgba(255,255,255,0.05);
+  --external-bg: #0b0e14;
+  --external-border: #2d3640;
+  --external-color: #5c6773;
+  --slowest-bg: #ff3333;
+  --slowest-border: #e6b450;
+  --slowest-fg: #0f1419;
+  --slowest-sub-fg: rgba(15,20,25,0.85);
+  --func-name-color: #e6b450;
+  --location-color: #5c6773;
+  --duration-color: #95e6cb;
+  --times-color: #5c6773;
+  --badge-bg: #2d3640;
+  --badge-color: #bfbab0;
+  --slowest-badge-bg: rgba(15,20,25,0.2);
+  --slowest-badge-color: #0f1419;
+  --toggle-color: #5c6773;
+  --toggle-hover-color: #e6b450;
+}}
+body {{ font-family: 'Segoe UI', -apple-system, BlinkMacSystemFont, sans-serif; margin: 24px; background: var(--bg); color: var(--fg); }}
+h1 {{ font-size: 1.5rem; color: var(--h1-color); margin-bottom: 16px; padding-bottom: 10px; border-bottom: 3px solid var(--h1-border); display: flex; justify-content: space-between; align-items: center; gap: 16px; }}
+.theme-switcher {{ font-size: 0.85rem; font-weight: 400; }}
+.theme-switcher select {{ font-size: 0.85rem; padding: 4px 8px; border-radius: 6px; border: 1px solid var(--tree-border); background: var(--node-bg); color: var(--fg); }}
+.summary {{ background: var(--summary-bg); padding: 14px 20px; border-radius: 8px; margin-bottom: 20px; display: flex; gap: 32px; flex-wrap: wrap; font-size: 0.9rem; }}
 .summary .item {{ display: flex; align-items: center; gap: 6px; }}
-.summary .label {{ font-weight: 600; color: #495057; }}
-.summary .value {{ color: #212529; }}
-.summary .slowest-name {{ color: #e63946; font-weight: 700; }}
+.summary .label {{ font-weight: 600; color: var(--label-color); }}
+.summary .value {{ color: var(--value-color); }}
+.summary .slowest-name {{ color: var(--slowest-name-color); font-weight: 700; }}
 .tree {{ font-size: 0.88rem; }}
-.tree ul {{ list-style: none; padding-left: 28px; border-left: 2px solid #dee2e6; margin: 0; }}
+.tree ul {{ list-style: none; padding-left: 28px; border-left: 2px solid var(--tree-border); margin: 0; }}
 .tree > ul {{ border-left: none; padding-left: 0; }}
 .tree li {{ position: relative; padding: 3px 0; }}
-.node {{ display: inline-flex; align-items: center; gap: 8px; padding: 5px 12px; border-radius: 6px; border: 1px solid #dee2e6; background: #fff; cursor: default; transition: all 0.15s; flex-wrap: wrap; }}
-.node:hover {{ box-shadow: 0 2px 8px rgba(0,0,0,0.08); }}
-.node.external {{ background: #f1f3f5; border-color: #ced4da; }}
-.node.external .func-name {{ color: #868e96; font-style: italic; }}
-.node.slowest {{ background: #e63946; border-color: #c1121f; }}
-.node.slowest .func-name {{ color: #fff; }}
+.node {{ display: inline-flex; align-items: center; gap: 8px; padding: 5px 12px; border-radius: 6px; border: 1px solid var(--node-border); background: var(--node-bg); cursor: default; transition: all 0.15s; flex-wrap: wrap; }}
+.node:hover {{ box-shadow: 0 2px 8px var(--node-hover-shadow); }}
+.node.external {{ background: var(--external-bg); border-color: var(--external-border); }}
+.node.external .func-name {{ color: var(--external-color); font-style: italic; }}
+.node.slowest {{ background: var(--slowest-bg); border-color: var(--slowest-border); }}
+.node.slowest .func-name {{ color: var(--slowest-fg); }}
 .node.slowest .location,
 .node.slowest .duration,
-.node.slowest .times {{ color: rgba(255,255,255,0.85); }}
-.func-name {{ font-weight: 600; color: #1a1a2e; font-family: 'SF Mono', 'Fira Code', 'Cascadia Code', monospace; }}
-.location {{ font-size: 0.82em; color: #6c757d; font-family: 'SF Mono', 'Fira Code', monospace; }}
-.duration {{ font-size: 0.82em; color: #4361ee; font-weight: 600; }}
-.times {{ font-size: 0.78em; color: #adb5bd; }}
-.lib-badge {{ font-size: 0.72em; background: #dee2e6; color: #495057; padding: 1px 8px; border-radius: 10px; font-weight: 500; }}
-.node.slowest .lib-badge {{ background: rgba(255,255,255,0.25); color: #fff; }}
-.toggle {{ display: inline-block; width: 18px; font-size: 0.75em; text-align: center; cursor: pointer; user-select: none; color: #868e96; font-weight: bold; flex-shrink: 0; }}
-.toggle:hover {{ color: #4361ee; }}
+.node.slowest .times {{ color: var(--slowest-sub-fg); }}
+.func-name {{ font-weight: 600; color: var(--func-name-color); font-family: 'SF Mono', 'Fira Code', 'Cascadia Code', monospace; }}
+.location {{ font-size: 0.82em; color: var(--location-color); font-family: 'SF Mono', 'Fira Code', monospace; }}
+.duration {{ font-size: 0.82em; color: var(--duration-color); font-weight: 600; }}
+.times {{ font-size: 0.78em; color: var(--times-color); }}
+.lib-badge {{ font-size: 0.72em; background: var(--badge-bg); color: var(--badge-color); padding: 1px 8px; border-radius: 10px; font-weight: 500; }}
+.node.slowest .lib-badge {{ background: var(--slowest-badge-bg); color: var(--slowest-badge-color); }}
+.toggle {{ display: inline-block; width: 18px; font-size: 0.75em; text-align: center; cursor: pointer; user-select: none; color: var(--toggle-color); font-weight: bold; flex-shrink: 0; }}
+.toggle:hover {{ color: var(--toggle-hover-color); }}
 .hidden {{ display: none; }}
+.search-bar {{ display: flex; align-items: center; gap: 12px; margin-bottom: 16px; flex-wrap: wrap; font-size: 0.88rem; }}
+.search-bar input[type="text"] {{ flex: 1 1 240px; padding: 7px 12px; border-radius: 6px; border: 1px solid var(--tree-border); background: var(--node-bg); color: var(--fg); font-size: 0.9rem; }}
+.search-bar label {{ display: flex; align-items: center; gap: 6px; color: var(--label-color); }}
+.search-bar input[type="number"] {{ width: 90px; padding: 6px 8px; border-radius: 6px; border: 1px solid var(--tree-border); background: var(--node-bg); color: var(--fg); }}
+.search-bar .match-count {{ color: var(--label-color); }}
+.filtered-out {{ display: none !important; }}
+.stats-table {{ width: 100%; border-collapse: collapse; margin-bottom: 24px; font-size: 0.85rem; }}
+.stats-table th, .stats-table td {{ text-align: left; padding: 7px 14px; border-bottom: 1px solid var(--tree-border); }}
+.stats-table td.num, .stats-table th.num {{ text-align: right; font-variant-numeric: tabular-nums; }}
+.stats-table thead th {{ cursor: pointer; user-select: none; color: var(--label-color); font-weight: 600; white-space: nowrap; }}
+.stats-table thead th:hover {{ color: var(--duration-color); }}
+.stats-table thead th.sorted::after {{ content: ' ▼'; font-size: 0.8em; }}
+.stats-table thead th.sorted.asc::after {{ content: ' ▲'; }}
+.stats-table td.func-cell {{ font-family: 'SF Mono', 'Fira Code', 'Cascadia Code', monospace; }}
+.node.elided {{ border-style: dashed; cursor: pointer; }}
+.node.elided .elided-summary {{ color: var(--label-color); font-style: italic; }}
+.node.elided:hover {{ box-shadow: 0 2px 8px var(--node-hover-shadow); }}
+.elided-data {{ display: none; }}
 </style>
 </head>
 <body>
-<h1>Call Profile: {api_name}</h1>
+<h1>Call Profile: {api_name}<span class="theme-switcher">Theme: <select id="theme-select">
+<option value="light">Light</option>
+<option value="dark">Dark</option>
+<option value="ayu">Ayu</option>
+</select></span></h1>
 "#,
         api_name = html_escape(api_name)
     )
@@ -92,7 +257,7 @@ h1 {{ font-size: 1.5rem; color: #1a1a2e; margin-bottom: 16px; padding-bottom: 10
     .unwrap();
 
     if let Some(sid) = slowest_id {
-        if let Some(node) = find_node_by_id(root, sid) {
+        if let Some(node) = root.find_by_id(sid) {
             write!(
                 html,
                 r#"<div class="item"><span class="label">Slowest Function:</span><span class="slowest-name">{} ({})</span></div>"#,
@@ -112,13 +277,39 @@ h1 {{ font-size: 1.5rem; color: #1a1a2e; margin-bottom: 16px; padding-bottom: 10
     .unwrap();
     write!(html, "</div>\n").unwrap();
 
+    // Per-function aggregated statistics table
+    let stats = root.aggregate_stats();
+    render_stats_table(&mut html, &stats);
+
+    // Search and filter bar
+    write!(
+        html,
+        r#"<div class="search-bar">
+<input type="text" id="search-input" placeholder="Search function / module / library...">
+<label>Min duration (ms): <input type="number" id="min-duration-input" min="0" step="any" value="0"></label>
+<span class="match-count" id="match-count"></span>
+</div>
+"#
+    )
+    .unwrap();
+
     // Tree
     let mut counter: usize = 0;
+    let mut nodes_rendered: usize = 0;
     write!(html, r#"<div class="tree"><ul>"#).unwrap();
-    render_node(&mut html, root, &slowest_id, &mut counter);
+    render_node(
+        &mut html,
+        root,
+        &slowest_id,
+        &mut counter,
+        0,
+        limits,
+        &mut nodes_rendered,
+    );
     write!(html, "</ul></div>\n").unwrap();
 
-    // JavaScript for toggle
+    // JavaScript for toggle, elided-subtree expansion, theme switching,
+    // search filtering and stats-table sorting
     write!(
         html,
         r#"<script>
@@ -134,6 +325,238 @@ function toggle(el) {{
         el.textContent = '\u25B6';
     }}
 }}
+
+function formatDurationJs(ns) {{
+    if (ns < 1000) return ns + 'ns';
+    if (ns < 1000000) return (ns / 1000).toFixed(2) + '\u00B5s';
+    if (ns < 1000000000) return (ns / 1000000).toFixed(2) + 'ms';
+    return (ns / 1000000000).toFixed(3) + 's';
+}}
+
+function buildNodeLi(data) {{
+    var li = document.createElement('li');
+    li.id = 'node-' + data.id;
+
+    var div = document.createElement('div');
+    var classes = ['node'];
+    if (data.external) classes.push('external');
+    if (data.slowest) classes.push('slowest');
+    div.className = classes.join(' ');
+    div.setAttribute('data-func', data.func);
+    div.setAttribute('data-module', data.module);
+    div.setAttribute('data-lib', data.lib);
+    div.setAttribute('data-dur-ns', data.dur_ns);
+
+    var hasChildren = data.children && data.children.length > 0;
+    if (hasChildren || data.elided_count) {{
+        var toggleSpan = document.createElement('span');
+        toggleSpan.className = 'toggle';
+        toggleSpan.textContent = '\u25BC';
+        toggleSpan.onclick = function() {{ toggle(toggleSpan); }};
+        div.appendChild(toggleSpan);
+    }}
+
+    var funcSpan = document.createElement('span');
+    funcSpan.className = 'func-name';
+    funcSpan.textContent = data.func;
+    if (data.raw) funcSpan.title = data.raw;
+    div.appendChild(funcSpan);
+
+    if (data.file) {{
+        var locSpan = document.createElement('span');
+        locSpan.className = 'location';
+        var shortFile = data.file.split(/[\\/]/).pop();
+        locSpan.textContent = shortFile + ':' + data.line;
+        div.appendChild(locSpan);
+    }}
+
+    var durSpan = document.createElement('span');
+    durSpan.className = 'duration';
+    durSpan.textContent = formatDurationJs(data.dur_ns);
+    div.appendChild(durSpan);
+
+    var timesSpan = document.createElement('span');
+    timesSpan.className = 'times';
+    timesSpan.textContent = '[start: ' + formatDurationJs(data.start_ns) + ' | end: ' + formatDurationJs(data.end_ns) + ']';
+    div.appendChild(timesSpan);
+
+    if (data.external && data.lib) {{
+        var badge = document.createElement('span');
+        badge.className = 'lib-badge';
+        badge.textContent = data.lib;
+        div.appendChild(badge);
+    }}
+
+    li.appendChild(div);
+
+    if (hasChildren || data.elided_count) {{
+        var ul = document.createElement('ul');
+        data.children.forEach(function(child) {{
+            ul.appendChild(buildNodeLi(child));
+        }});
+        if (data.elided_count) {{
+            var summaryLi = document.createElement('li');
+            summaryLi.className = 'elided-node';
+            var summaryDiv = document.createElement('div');
+            summaryDiv.className = 'node elided';
+            var summarySpan = document.createElement('span');
+            summarySpan.className = 'elided-summary';
+            summarySpan.textContent = '… ' + data.elided_count + ' more frame' +
+                (data.elided_count === 1 ? '' : 's') + ', ' + formatDurationJs(data.elided_dur_ns) + ' total';
+            summaryDiv.appendChild(summarySpan);
+            summaryLi.appendChild(summaryDiv);
+            ul.appendChild(summaryLi);
+        }}
+        li.appendChild(ul);
+    }}
+
+    return li;
+}}
+
+function expandElided(el) {{
+    var li = el.closest('li.elided-node');
+    var script = li.querySelector(':scope > script.elided-data');
+    if (!script) return;
+    var childrenData = JSON.parse(script.textContent);
+    var parent = li.parentNode;
+    childrenData.forEach(function(child) {{
+        parent.insertBefore(buildNodeLi(child), li);
+    }});
+    parent.removeChild(li);
+}}
+
+(function() {{
+    var THEME_KEY = 'callprofiler-theme';
+    var select = document.getElementById('theme-select');
+    var stored = localStorage.getItem(THEME_KEY) || 'light';
+
+    function applyTheme(theme) {{
+        document.documentElement.setAttribute('data-theme', theme);
+        select.value = theme;
+    }}
+
+    applyTheme(stored);
+    select.addEventListener('change', function() {{
+        localStorage.setItem(THEME_KEY, select.value);
+        applyTheme(select.value);
+    }});
+}})();
+
+(function() {{
+    var searchInput = document.getElementById('search-input');
+    var minDurationInput = document.getElementById('min-duration-input');
+    var matchCount = document.getElementById('match-count');
+
+    function expandAncestors(li) {{
+        var parent = li.parentElement && li.parentElement.closest('li');
+        while (parent) {{
+            var ul = parent.querySelector(':scope > ul');
+            var toggleEl = parent.querySelector(':scope > .node > .toggle');
+            if (ul && ul.classList.contains('hidden')) {{
+                ul.classList.remove('hidden');
+                if (toggleEl) toggleEl.textContent = '▼';
+            }}
+            parent.classList.remove('filtered-out');
+            parent = parent.parentElement && parent.parentElement.closest('li');
+        }}
+    }}
+
+    function applyFilter() {{
+        var query = searchInput.value.trim().toLowerCase();
+        var minDurationNs = (parseFloat(minDurationInput.value) || 0) * 1000000;
+        var matches = 0;
+        var allLis = document.querySelectorAll('.tree li');
+
+        allLis.forEach(function(li) {{
+            li.classList.add('filtered-out');
+        }});
+
+        allLis.forEach(function(li) {{
+            var node = li.querySelector(':scope > .node');
+            if (!node) return;
+
+            var func = (node.getAttribute('data-func') || '').toLowerCase();
+            var mod = (node.getAttribute('data-module') || '').toLowerCase();
+            var lib = (node.getAttribute('data-lib') || '').toLowerCase();
+            var durNs = parseFloat(node.getAttribute('data-dur-ns')) || 0;
+
+            var matchesQuery = !query || func.includes(query) || mod.includes(query) || lib.includes(query);
+            var matchesDuration = durNs >= minDurationNs;
+            var isMatch = matchesQuery && matchesDuration;
+
+            if (isMatch) {{
+                matches++;
+                li.classList.remove('filtered-out');
+                expandAncestors(li);
+            }}
+        }});
+
+        if (query || minDurationNs > 0) {{
+            matchCount.textContent = matches + ' match' + (matches === 1 ? '' : 'es');
+        }} else {{
+            matchCount.textContent = '';
+        }}
+    }}
+
+    searchInput.addEventListener('input', applyFilter);
+    minDurationInput.addEventListener('input', applyFilter);
+}})();
+
+(function() {{
+    var table = document.getElementById('stats-table');
+    if (!table) return;
+    var tbody = table.querySelector('tbody');
+    var headers = table.querySelectorAll('thead th');
+    var sortKey = 'self';
+    var sortAsc = false;
+
+    var attrForKey = {{
+        function: null,
+        calls: 'data-calls',
+        total: 'data-total-ns',
+        self: 'data-self-ns',
+        avg: 'data-avg-ns',
+        max: 'data-max-ns',
+    }};
+
+    function sortRows() {{
+        var rows = Array.from(tbody.querySelectorAll('tr'));
+        var attr = attrForKey[sortKey];
+        rows.sort(function(a, b) {{
+            var va, vb;
+            if (attr) {{
+                va = parseFloat(a.getAttribute(attr)) || 0;
+                vb = parseFloat(b.getAttribute(attr)) || 0;
+            }} else {{
+                va = a.querySelector('.func-cell').textContent;
+                vb = b.querySelector('.func-cell').textContent;
+            }}
+            var cmp = va < vb ? -1 : va > vb ? 1 : 0;
+            return sortAsc ? cmp : -cmp;
+        }});
+        rows.forEach(function(row) {{ tbody.appendChild(row); }});
+
+        headers.forEach(function(th) {{
+            th.classList.toggle('sorted', th.getAttribute('data-sort-key') === sortKey);
+            th.classList.toggle('asc', th.getAttribute('data-sort-key') === sortKey && sortAsc);
+        }});
+    }}
+
+    headers.forEach(function(th) {{
+        th.addEventListener('click', function() {{
+            var key = th.getAttribute('data-sort-key');
+            if (key === sortKey) {{
+                sortAsc = !sortAsc;
+            }} else {{
+                sortKey = key;
+                sortAsc = false;
+            }}
+            sortRows();
+        }});
+    }});
+
+    sortRows();
+}})();
 </script>
 </body>
 </html>"#
@@ -143,33 +566,67 @@ function toggle(el) {{
     html
 }
 
-fn find_node_by_id(node: &CallNode, target_id: usize) -> Option<&CallNode> {
-    let mut counter: usize = 0;
-    find_node_recursive(node, target_id, &mut counter)
+fn count_nodes(node: &CallNode) -> usize {
+    1 + node.children.iter().map(count_nodes).sum::<usize>()
 }
 
-fn find_node_recursive<'a>(
-    node: &'a CallNode,
-    target_id: usize,
-    counter: &mut usize,
-) -> Option<&'a CallNode> {
-    let my_id = *counter;
-    *counter += 1;
+fn render_stats_table(html: &mut String, stats: &HashMap<(String, String), FuncStats>) {
+    let mut rows: Vec<(&(String, String), &FuncStats)> = stats.iter().collect();
+    // Default sort: self time descending, to surface aggregate hotspots.
+    rows.sort_by_key(|(_, s)| std::cmp::Reverse(s.self_duration_ns));
 
-    if my_id == target_id {
-        return Some(node);
+    write!(html, r#"<table class="stats-table" id="stats-table"><thead><tr>"#).unwrap();
+    let columns = [
+        ("function", "Function", false),
+        ("calls", "Calls", true),
+        ("total", "Total", true),
+        ("self", "Self", true),
+        ("avg", "Avg", true),
+        ("max", "Max", true),
+    ];
+    for (key, label, is_num) in columns {
+        let class = if is_num { " num" } else { "" };
+        let sorted_class = if key == "self" { " sorted" } else { "" };
+        write!(
+            html,
+            r#"<th class="{class}{sorted_class}" data-sort-key="{key}">{label}</th>"#
+        )
+        .unwrap();
     }
+    write!(html, "</tr></thead><tbody>").unwrap();
 
-    for child in &node.children {
-        if let Some(found) = find_node_recursive(child, target_id, counter) {
-            return Some(found);
-        }
+    for ((module, func), s) in rows {
+        let avg_ns = s.total_duration_ns.checked_div(s.calls).unwrap_or(0);
+        let qualified_name = if module.is_empty() {
+            func.clone()
+        } else {
+            format!("{module}.{func}")
+        };
+        write!(
+            html,
+            r#"<tr data-calls="{calls}" data-total-ns="{total}" data-self-ns="{selfns}" data-avg-ns="{avg}" data-max-ns="{max}">"#,
+            calls = s.calls,
+            total = s.total_duration_ns,
+            selfns = s.self_duration_ns,
+            avg = avg_ns,
+            max = s.max_duration_ns
+        )
+        .unwrap();
+        write!(
+            html,
+            r#"<td class="func-cell">{}</td><td class="num">{}</td><td class="num">{}</td><td class="num">{}</td><td class="num">{}</td><td class="num">{}</td>"#,
+            html_escape(&qualified_name),
+            s.calls,
+            format_duration(s.total_duration_ns),
+            format_duration(s.self_duration_ns),
+            format_duration(avg_ns),
+            format_duration(s.max_duration_ns)
+        )
+        .unwrap();
+        write!(html, "</tr>\n").unwrap();
     }
-    None
-}
 
-fn count_nodes(node: &CallNode) -> usize {
-    1 + node.children.iter().map(count_nodes).sum::<usize>()
+    write!(html, "</tbody></table>\n").unwrap();
 }
 
 fn render_node(
@@ -177,14 +634,18 @@ fn render_node(
     node: &CallNode,
     slowest_id: &Option<usize>,
     counter: &mut usize,
+    depth: usize,
+    limits: &RenderLimits,
+    nodes_rendered: &mut usize,
 ) {
     let my_id = *counter;
     *counter += 1;
+    *nodes_rendered += 1;
 
     let is_slowest = slowest_id.map_or(false, |id| id == my_id);
     let has_children = !node.children.is_empty();
 
-    write!(html, "<li>").unwrap();
+    write!(html, r#"<li id="node-{my_id}">"#).unwrap();
 
     // Build CSS classes
     let mut classes = String::from("node");
@@ -195,7 +656,15 @@ fn render_node(
         classes.push_str(" slowest");
     }
 
-    write!(html, r#"<div class="{classes}">"#).unwrap();
+    write!(
+        html,
+        r#"<div class="{classes}" data-func="{func}" data-module="{module}" data-lib="{lib}" data-dur-ns="{dur}">"#,
+        func = html_escape(&node.func_name),
+        module = html_escape(&node.module_name),
+        lib = html_escape(&node.library_name),
+        dur = node.duration_ns
+    )
+    .unwrap();
 
     // Toggle button
     if has_children {
@@ -207,12 +676,20 @@ fn render_node(
         .unwrap();
     }
 
-    // Function name
-    write!(
-        html,
-        r#"<span class="func-name">{}</span>"#,
-        html_escape(&node.func_name)
-    )
+    // Function name (with the raw mangled symbol as a tooltip, if demangled)
+    match &node.raw_symbol {
+        Some(raw) => write!(
+            html,
+            r#"<span class="func-name" title="{}">{}</span>"#,
+            html_escape(raw),
+            html_escape(&node.func_name)
+        ),
+        None => write!(
+            html,
+            r#"<span class="func-name">{}</span>"#,
+            html_escape(&node.func_name)
+        ),
+    }
     .unwrap();
 
     // File location
@@ -255,14 +732,155 @@ fn render_node(
 
     write!(html, "</div>").unwrap();
 
-    // Render children
+    // Render children, eliding the subtree behind a collapsed placeholder
+    // once the depth or node budget is exhausted.
     if has_children {
+        let exceeds_depth = depth + 1 > limits.max_depth;
+        let exceeds_budget = *nodes_rendered >= limits.max_nodes;
+
         write!(html, "<ul>").unwrap();
-        for child in &node.children {
-            render_node(html, child, slowest_id, counter);
+        if exceeds_depth || exceeds_budget {
+            render_elided_placeholder(html, node, slowest_id, counter, limits);
+        } else {
+            for child in &node.children {
+                render_node(html, child, slowest_id, counter, depth + 1, limits, nodes_rendered);
+            }
         }
         write!(html, "</ul>").unwrap();
     }
 
     write!(html, "</li>\n").unwrap();
 }
+
+/// Replace an elided subtree with a single `<li>` summarizing what was cut
+/// (node count, total duration, slowest child), embedding the subtree as
+/// JSON so `expandElided` can build it on demand instead of bloating the
+/// initial page. The embedded JSON is itself subject to `limits`, so a
+/// single placeholder can never ship an unbounded number of descendants:
+/// once the budget for that embed is exhausted, deeper frames collapse
+/// into a nested summary (`elided_count`/`elided_dur_ns`) rather than being
+/// serialized in full.
+fn render_elided_placeholder(
+    html: &mut String,
+    node: &CallNode,
+    slowest_id: &Option<usize>,
+    counter: &mut usize,
+    limits: &RenderLimits,
+) {
+    let elided_count: usize = node.children.iter().map(count_nodes).sum();
+    let elided_duration_ns: u64 = node.children.iter().map(|c| c.duration_ns).sum();
+    let slowest_child = node.children.iter().max_by_key(|c| c.duration_ns);
+
+    let mut summary = format!(
+        "\u{2026} {} more frame{}, {} total",
+        elided_count,
+        if elided_count == 1 { "" } else { "s" },
+        format_duration(elided_duration_ns)
+    );
+    if let Some(sc) = slowest_child {
+        write!(
+            summary,
+            ", slowest child: {} ({})",
+            sc.func_name,
+            format_duration(sc.duration_ns)
+        )
+        .unwrap();
+    }
+
+    write!(html, r#"<li class="elided-node">"#).unwrap();
+    write!(
+        html,
+        r#"<div class="node elided" onclick="expandElided(this)"><span class="toggle">{}</span><span class="elided-summary">{}</span></div>"#,
+        '\u{25B6}',
+        html_escape(&summary)
+    )
+    .unwrap();
+
+    write!(html, r#"<script type="application/json" class="elided-data">["#).unwrap();
+    let mut embedded = 0usize;
+    for (i, child) in node.children.iter().enumerate() {
+        if i > 0 {
+            write!(html, ",").unwrap();
+        }
+        write_node_json(html, child, slowest_id, counter, limits, 0, &mut embedded);
+    }
+    write!(html, "]</script>").unwrap();
+    write!(html, "</li>\n").unwrap();
+}
+
+/// Serialize a node to JSON for on-demand client-side rendering by
+/// `expandElided`. Assigns pre-order ids via `counter` so ids stay
+/// consistent with [`CallNode::find_by_id`] across the whole tree.
+///
+/// `limits` bounds this embed the same way [`render_node`] bounds the
+/// initial page: once `embedded` (this embed's own node budget, separate
+/// from the page-wide one) or `depth` runs out, the remaining descendants
+/// are folded into an `elided_count`/`elided_dur_ns` summary instead of
+/// being recursed into, so one placeholder can't smuggle a whole huge
+/// subtree into the page as inline JSON.
+fn write_node_json(
+    html: &mut String,
+    node: &CallNode,
+    slowest_id: &Option<usize>,
+    counter: &mut usize,
+    limits: &RenderLimits,
+    depth: usize,
+    embedded: &mut usize,
+) {
+    let my_id = *counter;
+    *counter += 1;
+    *embedded += 1;
+    let is_slowest = slowest_id.map_or(false, |id| id == my_id);
+
+    write!(
+        html,
+        r#"{{"id":{id},"func":"{func}","raw":{raw},"module":"{module}","lib":"{lib}","file":"{file}","line":{line},"start_ns":{start},"end_ns":{end},"dur_ns":{dur},"external":{external},"slowest":{slowest},"#,
+        id = my_id,
+        func = json_escape(&node.func_name),
+        raw = match &node.raw_symbol {
+            Some(r) => format!("\"{}\"", json_escape(r)),
+            None => "null".to_string(),
+        },
+        module = json_escape(&node.module_name),
+        lib = json_escape(&node.library_name),
+        file = json_escape(&node.file_path),
+        line = node.line_number,
+        start = node.start_time_ns,
+        end = node.end_time_ns,
+        dur = node.duration_ns,
+        external = node.is_external,
+        slowest = is_slowest,
+    )
+    .unwrap();
+
+    let exceeds_depth = depth + 1 > limits.max_depth;
+    let exceeds_budget = *embedded >= limits.max_nodes;
+
+    if !node.children.is_empty() && (exceeds_depth || exceeds_budget) {
+        let elided_count: usize = node.children.iter().map(count_nodes).sum();
+        let elided_duration_ns: u64 = node.children.iter().map(|c| c.duration_ns).sum();
+        // These descendants aren't serialized, but they still occupy ids in
+        // the tree's pre-order numbering (shared with find_by_id/
+        // find_slowest_id), so counter must skip over all of them - not
+        // just this node - or every id assigned afterward drifts out of
+        // sync with the rest of the document.
+        *counter += elided_count;
+        write!(
+            html,
+            r#""elided_count":{count},"elided_dur_ns":{dur},"children":[]}}"#,
+            count = elided_count,
+            dur = elided_duration_ns
+        )
+        .unwrap();
+        return;
+    }
+
+    write!(html, r#""children":["#).unwrap();
+    for (i, child) in node.children.iter().enumerate() {
+        if i > 0 {
+            write!(html, ",").unwrap();
+        }
+        write_node_json(html, child, slowest_id, counter, limits, depth + 1, embedded);
+    }
+    write!(html, "]}}").unwrap();
+}